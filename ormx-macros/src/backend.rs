@@ -0,0 +1,59 @@
+/// Abstracts over the SQL dialect differences between the database backends ormx supports.
+pub trait Backend: Default {
+    /// The character used to quote identifiers (table and column names) in generated SQL.
+    const IDENT_QUOTE: char;
+
+    /// Wraps `ident` in this backend's identifier quoting character, e.g. `` `col` `` for MySQL
+    /// or `"col"` for Postgres/SQLite.
+    fn quote_ident(ident: &str) -> String {
+        format!("{0}{1}{0}", Self::IDENT_QUOTE, ident)
+    }
+
+    /// The SQL expression that evaluates to the current timestamp on this backend, used for
+    /// auto-managed `#[ormx(updated_at)]` columns.
+    fn now_expr() -> &'static str;
+
+    /// The bind parameter placeholder for the `index`-th argument (0-based) of a query, e.g.
+    /// `$1`, `$2`, .. for Postgres, or `?` for MySQL/SQLite.
+    fn placeholder(index: usize) -> String {
+        let _ = index;
+        "?".to_owned()
+    }
+}
+
+#[derive(Default)]
+pub struct MySql;
+
+impl Backend for MySql {
+    const IDENT_QUOTE: char = '`';
+
+    fn now_expr() -> &'static str {
+        "NOW()"
+    }
+}
+
+#[derive(Default)]
+pub struct Postgres;
+
+impl Backend for Postgres {
+    const IDENT_QUOTE: char = '"';
+
+    fn now_expr() -> &'static str {
+        "now()"
+    }
+
+    fn placeholder(index: usize) -> String {
+        format!("${}", index + 1)
+    }
+}
+
+#[derive(Default)]
+pub struct Sqlite;
+
+impl Backend for Sqlite {
+    const IDENT_QUOTE: char = '"';
+
+    fn now_expr() -> &'static str {
+        "CURRENT_TIMESTAMP"
+    }
+}
@@ -43,6 +43,8 @@ mod utils;
 /// This struct will contain all fields of the struct, except
 /// - the ID
 /// - fields annotated with `#[ormx(default)]`
+/// - fields annotated with `#[ormx(created_at)]` / `#[ormx(updated_at)]` (or flagged as such by
+///   the table-level `#[ormx(timestamps)]` shorthand)
 ///
 /// since the value of these fields will be generated by the database.
 /// By default, this struct will be named `Insert{struct_name}`, though this can be changed by
@@ -85,6 +87,52 @@ mod utils;
 /// By default, the function will be named `set_{field_name)`, though this can be changed by
 /// supplying a custom name: `#[ormx(set = set_name)]`.
 ///
+/// # Flattening
+/// Fields annotated with `#[ormx(flatten)]` are treated as embedded structs which themselves
+/// implement `cherry::Schema` (and thus `FromRow`), rather than as a single column.
+/// The nested struct's columns are spliced into `columns()` in declaration order, and its rows
+/// are decoded from (and arguments written into) the very same row/argument list as the
+/// containing struct - there is no prefixing, so column names must be unique across the whole
+/// composition. This allows reusable column groups (e.g. an `Audit { created_by, created_at }`
+/// struct) to be composed into multiple tables.
+///
+/// # Ordinal decoding
+/// By default, `from_row` decodes each field by column name. Adding the table-level
+/// `#[ormx(ordinal)]` option switches to positional decoding (`row.try_get(i)`), which avoids the
+/// per-column name lookup sqlx does for string keys - worthwhile when decoding large result sets
+/// from a `SELECT` whose column order is fixed and known.
+/// Fields are auto-assigned an index in declaration order; `#[ormx(ordinal = N)]` on a field
+/// overrides its index, and the remaining fields fill in around it. Two fields resolving to the
+/// same index is a compile error. `columns()` emits columns ordered by their resolved index (which
+/// is declaration order when no field overrides it), so a generated `SELECT col0, col1, ..`
+/// matches the positions `from_row` reads.
+///
+/// # Timestamps
+/// Fields annotated `#[ormx(created_at)]` or `#[ormx(updated_at)]` are treated as audit
+/// timestamps: like `default` fields, they are left out of the generated `Insert{Struct}` so the
+/// database can populate them, and an `#[ormx(updated_at)]` field is set to the backend's
+/// `now()`/`CURRENT_TIMESTAMP` expression on every generated update, instead of the struct's own
+/// value, so callers never have to remember to bump it by hand.
+/// The table-level `#[ormx(timestamps)]` option is shorthand for applying `created_at`/
+/// `updated_at` to fields of those names.
+///
+/// # Tracking changes
+/// `#[ormx(track_changes)]` generates a `{struct_name}Changes` sibling struct, holding an
+/// `Option<FieldType>` per non-id field plus a `set_{field_name}` setter for each one that marks
+/// it dirty; `#[ormx(flatten)]`, `#[ormx(created_at)]` and `#[ormx(updated_at)]` fields get no
+/// slot or setter, and an `updated_at` column is bumped automatically whenever anything is dirty. Its `apply(&id, conn)` method issues a single `UPDATE` touching only the fields that
+/// were actually set, instead of the whole row - or no SQL at all if nothing was changed. This
+/// gives "modify then save" ergonomics without clobbering columns that were concurrently updated.
+///
+/// # Query builder
+/// ormx always generates `{Struct}::select()`, returning a `{Struct}Select` builder for ad-hoc
+/// filtering without dropping to hand-written `query_as!`: `.where_("age > ?")` appends a raw
+/// `WHERE` fragment (ANDed with any previous ones), `.bind(value)` supplies the matching argument,
+/// and `.order_by(..)`/`.limit(..)` refine the query. `.fetch_one`/`.fetch_optional`/`.fetch_all`
+/// run it against a connection and decode rows through the struct's own `Schema::from_row`.
+/// The builder's `?` placeholders are renumbered to each backend's native bind syntax (`$n` for
+/// Postgres, `?` for MySQL/SQLite) when the query is built.
+///
 /// # Custom types
 /// When using custom types (which implement `sqlx::Type`), the field has to annotated with
 /// `#[ormx(custom_type)]`.
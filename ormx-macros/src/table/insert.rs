@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{backend::Backend, table::Table};
+
+/// Generates the `Insert{Struct}` helper struct for `#[ormx(insertable)]`: every field except the
+/// id, `#[ormx(default)]` fields, and auto-managed timestamp columns (`#[ormx(created_at)]` /
+/// `#[ormx(updated_at)]`) - the database populates all of those - plus an `insert` method that
+/// writes the remaining columns.
+///
+/// Returns `None` if the table isn't `#[ormx(insertable)]`.
+pub fn impl_insertable<B: Backend>(table: &Table<B>) -> Option<TokenStream> {
+    let insert_ident = table.insertable.as_ref()?;
+
+    let fields: Vec<_> = table
+        .fields
+        .iter()
+        .filter(|f| f.field != table.id.field && !f.default && !f.created_at && !f.updated_at)
+        .collect();
+
+    let struct_fields = fields.iter().map(|f| {
+        let field = &f.field;
+        let ty = &f.ty;
+        quote! { pub #field: #ty }
+    });
+
+    let table_ident = &table.ident;
+    let table_name = B::quote_ident(&table.table);
+
+    let columns: Vec<String> = fields.iter().map(|f| B::quote_ident(&f.column())).collect();
+    let placeholders: Vec<String> = (0..fields.len()).map(B::placeholder).collect();
+    let sql = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        table_name,
+        columns.join(", "),
+        placeholders.join(", ")
+    );
+
+    let bind_args: proc_macro2::TokenStream = fields
+        .iter()
+        .map(|f| format!(" arguments.add(&self.{}); ", f.field))
+        .collect::<String>()
+        .parse()
+        .unwrap();
+
+    Some(quote! {
+        pub struct #insert_ident {
+            #(#struct_fields,)*
+        }
+
+        impl #insert_ident {
+            fn insert_sql() -> &'static str {
+                #sql
+            }
+
+            fn insert_arguments<'a>(&'a self, arguments: &mut cherry::types::Arguments<'a>) {
+                use cherry::sqlx::Arguments as OtherArguments;
+                #bind_args
+            }
+
+            /// Inserts this row, returning the freshly-constructed `#table_ident`.
+            pub async fn insert(
+                &self,
+                conn: &mut cherry::types::Connection,
+            ) -> Result<#table_ident, cherry::error::Error> {
+                let mut arguments = cherry::types::Arguments::default();
+                self.insert_arguments(&mut arguments);
+                cherry::fetch_one(Self::insert_sql(), &arguments, conn).await
+            }
+        }
+    })
+}
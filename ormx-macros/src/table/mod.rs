@@ -0,0 +1,183 @@
+use syn::{Ident, Type};
+
+use crate::backend::Backend;
+
+mod insert;
+mod query_builder;
+mod schema;
+mod track_changes;
+mod update;
+
+pub use insert::impl_insertable;
+pub use query_builder::impl_query_builder;
+pub use schema::impl_schema;
+pub use track_changes::impl_track_changes;
+pub use update::impl_update;
+
+/// Parsed representation of a `#[derive(ormx::Table)]` struct, generic over the target backend.
+pub struct Table<B: Backend> {
+    pub ident: Ident,
+    pub table: String,
+    pub id: TableField,
+    pub fields: Vec<TableField>,
+    /// Set by `#[ormx(insertable)]` (optionally `#[ormx(insertable = CreateUser)]`): the name of
+    /// the generated `Insert{Struct}` helper. `None` if the table isn't insertable.
+    pub insertable: Option<Ident>,
+    /// Set by the table-level `#[ormx(ordinal)]` option: `from_row` decodes fields positionally
+    /// (`row.try_get(i)`) instead of by column name.
+    pub ordinal: bool,
+    /// Set by the table-level `#[ormx(track_changes)]` option: generates a `{Struct}Changes`
+    /// dirty-field tracker alongside the `Table` impl.
+    pub track_changes: bool,
+    _backend: std::marker::PhantomData<B>,
+}
+
+/// A single field of a [`Table`], together with the `#[ormx(..)]` options that apply to it.
+pub struct TableField {
+    pub field: Ident,
+    pub ty: Type,
+    pub column_name: Option<String>,
+    pub default: bool,
+    /// Set by `#[ormx(flatten)]`: this field is an embedded struct that implements
+    /// `cherry::Schema`/`FromRow` itself, rather than a single column.
+    pub flatten: bool,
+    /// Set by `#[ormx(created_at)]` (or the table-level `#[ormx(timestamps)]` shorthand, for a
+    /// field named `created_at`): excluded from the generated `Insert{Struct}`, like `default`
+    /// fields, and left for the database to populate.
+    pub created_at: bool,
+    /// Set by `#[ormx(updated_at)]` (or `#[ormx(timestamps)]`, for a field named `updated_at`):
+    /// excluded from `Insert{Struct}` and set to [`Backend::now_expr`] on every generated update.
+    pub updated_at: bool,
+    /// Explicit positional index from `#[ormx(ordinal = N)]`, if the field overrides the
+    /// auto-assigned one.
+    pub ordinal_override: Option<usize>,
+    /// The resolved positional index, assigned by [`assign_ordinals`]. Only meaningful when the
+    /// table has `#[ormx(ordinal)]` set.
+    pub ordinal: usize,
+}
+
+impl TableField {
+    /// The column name this field is stored under, honouring a `#[ormx(column = "..")]` override.
+    pub fn column(&self) -> String {
+        self.column_name
+            .clone()
+            .unwrap_or_else(|| self.field.to_string())
+    }
+}
+
+/// Resolves the final [`TableField::ordinal`] of every field in declaration order: fields with an
+/// explicit `#[ormx(ordinal = N)]` keep their index, and all other fields are auto-assigned the
+/// lowest index not already taken. Errors if two fields end up with the same index.
+pub fn assign_ordinals(fields: &mut [TableField]) -> syn::Result<()> {
+    let mut taken = std::collections::HashSet::new();
+    for field in fields.iter() {
+        if let Some(i) = field.ordinal_override {
+            if !taken.insert(i) {
+                return Err(syn::Error::new_spanned(
+                    &field.field,
+                    format!("duplicate `#[ormx(ordinal = {})]`", i),
+                ));
+            }
+        }
+    }
+
+    let mut next = 0usize;
+    for field in fields.iter_mut() {
+        field.ordinal = match field.ordinal_override {
+            Some(i) => i,
+            None => {
+                while taken.contains(&next) {
+                    next += 1;
+                }
+                taken.insert(next);
+                next
+            }
+        };
+    }
+
+    Ok(())
+}
+
+/// Applies the table-level `#[ormx(timestamps)]` shorthand: flags the fields literally named
+/// `created_at` and `updated_at` as such, equivalent to annotating them individually with
+/// `#[ormx(created_at)]` / `#[ormx(updated_at)]`.
+pub fn apply_timestamps_shorthand(fields: &mut [TableField]) {
+    for field in fields.iter_mut() {
+        match field.field.to_string().as_str() {
+            "created_at" => field.created_at = true,
+            "updated_at" => field.updated_at = true,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use super::{Backend, Table, TableField};
+
+    /// Builds a plain, non-flatten, non-timestamp `TableField` named `name` of type `ty` for use
+    /// in generator tests.
+    pub fn field(name: &str, ty: &str) -> TableField {
+        TableField {
+            field: syn::parse_str(name).unwrap(),
+            ty: syn::parse_str(ty).unwrap(),
+            column_name: None,
+            default: false,
+            flatten: false,
+            created_at: false,
+            updated_at: false,
+            ordinal_override: None,
+            ordinal: 0,
+        }
+    }
+
+    /// Builds a minimal `Table<B>` fixture (not insertable, not ordinal, not track_changes -
+    /// override the relevant field after construction where a test needs it set).
+    pub fn table<B: Backend>(ident: &str, table: &str, id: TableField, fields: Vec<TableField>) -> Table<B> {
+        Table {
+            ident: syn::parse_str(ident).unwrap(),
+            table: table.to_owned(),
+            id,
+            fields,
+            insertable: None,
+            ordinal: false,
+            track_changes: false,
+            _backend: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assign_ordinals, test_util::field};
+
+    #[test]
+    fn assign_ordinals_fills_in_around_explicit_overrides() {
+        let mut fields = vec![field("a", "i32"), field("b", "i32"), field("c", "i32")];
+        fields[1].ordinal_override = Some(0);
+
+        assign_ordinals(&mut fields).unwrap();
+
+        assert_eq!(fields[0].ordinal, 1); // a: auto, skips 0 (taken by b)
+        assert_eq!(fields[1].ordinal, 0); // b: explicit
+        assert_eq!(fields[2].ordinal, 2); // c: auto
+    }
+
+    #[test]
+    fn assign_ordinals_rejects_duplicate_explicit_overrides() {
+        let mut fields = vec![field("a", "i32"), field("b", "i32")];
+        fields[0].ordinal_override = Some(0);
+        fields[1].ordinal_override = Some(0);
+
+        assert!(assign_ordinals(&mut fields).is_err());
+    }
+
+    #[test]
+    fn assign_ordinals_is_identity_without_overrides() {
+        let mut fields = vec![field("a", "i32"), field("b", "i32"), field("c", "i32")];
+
+        assign_ordinals(&mut fields).unwrap();
+
+        assert_eq!(fields.iter().map(|f| f.ordinal).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
@@ -8,6 +8,16 @@ use crate::{
 };
 
 pub fn impl_schema<B: Backend>(table: &Table<B>) -> TokenStream {
+    if table.ordinal && table.fields.iter().any(|f| f.flatten) {
+        return quote! {
+            compile_error!(
+                "`#[ormx(flatten)]` cannot be combined with `#[ormx(ordinal)]`: a flattened \
+                 field spans more than one column, so it cannot be assigned a single positional \
+                 slot"
+            );
+        };
+    }
+
     let table_ident = &table.ident;
     let name = name::<B>(table);
     let columns = columns::<B>(table);
@@ -36,24 +46,41 @@ fn name<B: Backend>(table: &Table<B>) -> TokenStream {
 }
 
 fn columns<B: Backend>(table: &Table<B>) -> TokenStream {
-    let fields : proc_macro2::TokenStream = table.fields
-        .iter()
-        .map(|s|
-            format!(" \"{}\"", s.column())
-        ).join(", ").parse().unwrap();
+    // Under `#[ormx(ordinal)]`, `from_row` reads each non-flattened field by its resolved
+    // positional index, so the emitted `SELECT` list must be ordered the same way, not by
+    // declaration order, or per-field `#[ormx(ordinal = N)]` overrides would desync the two.
+    let mut ordered: Vec<&TableField> = table.fields.iter().collect();
+    if table.ordinal {
+        ordered.sort_by_key(|field| field.ordinal);
+    }
+
+    let fields : proc_macro2::TokenStream = ordered
+        .into_iter()
+        .map(|s| {
+            if s.flatten {
+                let ty = &s.ty;
+                format!(" <{} as cherry::Schema>::columns()", quote!(#ty))
+            } else {
+                format!(" vec![{:?}]", B::quote_ident(&s.column()))
+            }
+        }).join(", ").parse().unwrap();
 
     quote! {
         fn columns() -> Vec<&'static str> {
-                vec![ #fields]
+                [#fields].concat()
             }
     }
 }
 
 fn arguments<B: Backend>(table: &Table<B>) -> TokenStream {
     let arguments : proc_macro2::TokenStream = table.fields
-        .iter().map(|s|
-        format!(" arguments.add(&self.{}); ", s.field)
-    ).collect::<String>().parse().unwrap();
+        .iter().map(|s| {
+            if s.flatten {
+                format!(" cherry::Schema::arguments(&self.{}, arguments); ", s.field)
+            } else {
+                format!(" arguments.add(&self.{}); ", s.field)
+            }
+        }).collect::<String>().parse().unwrap();
 
     quote! {
         fn arguments<'a>(&'a self, arguments: &mut cherry::types::Arguments<'a>) {
@@ -66,9 +93,16 @@ fn arguments<B: Backend>(table: &Table<B>) -> TokenStream {
 fn from_row<B: Backend>(table: &Table<B>) -> TokenStream {
     let from_row : proc_macro2::TokenStream = table.fields
         .iter()
-        .map(|field|
-            format!(" {0}: row.try_get(\"{1}\")?", field.field, field.column())
-        ).join(", ").parse().unwrap();
+        .map(|field| {
+            if field.flatten {
+                let ty = &field.ty;
+                format!(" {0}: <{1} as cherry::Schema>::from_row(row)?", field.field, quote!(#ty))
+            } else if table.ordinal {
+                format!(" {0}: row.try_get({1})?", field.field, field.ordinal)
+            } else {
+                format!(" {0}: row.try_get(\"{1}\")?", field.field, field.column())
+            }
+        }).join(", ").parse().unwrap();
 
     quote! {
         fn from_row(row: &cherry::types::Row) -> Result<Self, cherry::error::Error> {
@@ -76,4 +110,49 @@ fn from_row<B: Backend>(table: &Table<B>) -> TokenStream {
             Ok( Self { #from_row } )
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backend::Postgres,
+        table::{assign_ordinals, test_util},
+    };
+
+    #[test]
+    fn columns_follow_resolved_ordinal_order_not_declaration_order() {
+        let mut fields = vec![test_util::field("first", "i32"), test_util::field("second", "i32")];
+        fields[0].ordinal_override = Some(1);
+        fields[1].ordinal_override = Some(0);
+        assign_ordinals(&mut fields).unwrap();
+
+        let mut t: Table<Postgres> =
+            test_util::table("User", "users", test_util::field("id", "i32"), fields);
+        t.ordinal = true;
+
+        let generated = columns(&t).to_string();
+        // Each quoted column name is emitted as a string literal, so look for its escaped form.
+        let first_quoted = format!("{:?}", Postgres::quote_ident("first"));
+        let second_quoted = format!("{:?}", Postgres::quote_ident("second"));
+        let first_pos = generated.find(&first_quoted).unwrap();
+        let second_pos = generated.find(&second_quoted).unwrap();
+        assert!(
+            second_pos < first_pos,
+            "`second` has ordinal 0 and `first` has ordinal 1, so `second` must come first: {generated}"
+        );
+    }
+
+    #[test]
+    fn flatten_combined_with_ordinal_is_rejected_at_compile_time() {
+        let mut embedded = test_util::field("embedded", "Audit");
+        embedded.flatten = true;
+
+        let mut t: Table<Postgres> =
+            test_util::table("User", "users", test_util::field("id", "i32"), vec![embedded]);
+        t.ordinal = true;
+
+        let generated = impl_schema(&t).to_string();
+        assert!(generated.contains("compile_error"));
+    }
 }
\ No newline at end of file
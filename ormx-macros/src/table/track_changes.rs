@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    backend::Backend,
+    table::Table,
+};
+
+/// Generates the `{Struct}Changes` dirty-field tracker for `#[ormx(track_changes)]`: an
+/// `Option<FieldType>` per non-id, non-`flatten`, non-timestamp field, setters that mark a field dirty, and an
+/// `apply` that issues an `UPDATE` touching only the fields that were actually set - a no-op if
+/// none were. An `#[ormx(updated_at)]` column isn't itself tracked; it's bumped automatically
+/// whenever anything else is dirty, the same as the plain generated update statement.
+pub fn impl_track_changes<B: Backend>(table: &Table<B>) -> TokenStream {
+    let tracker_ident = format_ident!("{}Changes", table.ident);
+    let id_ty = &table.id.ty;
+    let id_column = B::quote_ident(&table.id.column());
+    let table_name = B::quote_ident(&table.table);
+    // `B` only exists at macro-expansion time, but the number of dirty fields - and hence each
+    // one's placeholder index - is only known at runtime, so bake in just which placeholder
+    // style this backend uses (mirrors `query_builder::impl_query_builder`).
+    let dollar_style = B::placeholder(1) != "?";
+
+    // `updated_at` isn't user-settable here - it's bumped automatically below whenever anything
+    // else is dirty - `created_at` is left to the database, and `flatten` fields span more than one
+    // column, so none of them gets a tracked `Option<FieldType>` slot.
+    let fields: Vec<_> = table
+        .fields
+        .iter()
+        .filter(|f| !f.created_at && !f.updated_at && !f.flatten && f.field != table.id.field)
+        .collect();
+
+    let updated_at_assignment = table.fields.iter().find(|f| f.updated_at).map(|f| {
+        let column = B::quote_ident(&f.column());
+        let now_expr = B::now_expr();
+        quote! {
+            if !assignments.is_empty() {
+                assignments.push(format!("{} = {}", #column, #now_expr));
+            }
+        }
+    });
+
+    let struct_fields = fields.iter().map(|f| {
+        let field = &f.field;
+        let ty = &f.ty;
+        quote! { #field: Option<#ty> }
+    });
+
+    let setters = fields.iter().map(|f| {
+        let field = &f.field;
+        let ty = &f.ty;
+        let setter = format_ident!("set_{}", field);
+        quote! {
+            pub fn #setter(&mut self, value: #ty) -> &mut Self {
+                self.#field = Some(value);
+                self
+            }
+        }
+    });
+
+    let apply_arms = fields.iter().map(|f| {
+        let field = &f.field;
+        let column = B::quote_ident(&f.column());
+        quote! {
+            if let Some(value) = &self.#field {
+                let placeholder = if #dollar_style {
+                    format!("${}", index + 1)
+                } else {
+                    "?".to_owned()
+                };
+                assignments.push(format!("{} = {}", #column, placeholder));
+                arguments.add(value);
+                index += 1;
+            }
+        }
+    });
+
+    quote! {
+        #[derive(Default)]
+        pub struct #tracker_ident {
+            #(#struct_fields,)*
+        }
+
+        impl #tracker_ident {
+            #(#setters)*
+
+            /// Applies every field that was set since this tracker was created, as a single
+            /// `UPDATE` scoped to `id`. Issues no SQL at all if nothing was changed.
+            pub async fn apply(
+                &self,
+                id: &#id_ty,
+                conn: &mut cherry::types::Connection,
+            ) -> Result<(), cherry::error::Error> {
+                use cherry::sqlx::Arguments as OtherArguments;
+
+                let mut assignments: Vec<String> = Vec::new();
+                let mut arguments = cherry::types::Arguments::default();
+                let mut index = 0usize;
+                #(#apply_arms)*
+                #updated_at_assignment
+
+                if assignments.is_empty() {
+                    return Ok(());
+                }
+
+                let id_placeholder = if #dollar_style {
+                    format!("${}", index + 1)
+                } else {
+                    "?".to_owned()
+                };
+                arguments.add(id);
+                let sql = format!(
+                    "UPDATE {} SET {} WHERE {} = {}",
+                    #table_name,
+                    assignments.join(", "),
+                    #id_column,
+                    id_placeholder
+                );
+                cherry::execute(&sql, arguments, conn).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backend::{MySql, Postgres},
+        table::test_util,
+    };
+
+    fn sample_table<B: Backend>() -> Table<B> {
+        let mut updated_at = test_util::field("updated_at", "cherry::types::Timestamp");
+        updated_at.updated_at = true;
+
+        let mut embedded = test_util::field("audit", "Audit");
+        embedded.flatten = true;
+
+        test_util::table(
+            "User",
+            "users",
+            test_util::field("id", "i32"),
+            vec![test_util::field("name", "String"), updated_at, embedded],
+        )
+    }
+
+    #[test]
+    fn tracker_excludes_id_and_flatten_fields() {
+        let generated = impl_track_changes(&sample_table::<Postgres>()).to_string();
+        assert!(generated.contains("set_name"));
+        assert!(!generated.contains("set_id"));
+        assert!(!generated.contains("set_audit"));
+    }
+
+    #[test]
+    fn updated_at_is_bumped_unconditionally_when_anything_else_is_dirty() {
+        let generated = impl_track_changes(&sample_table::<Postgres>()).to_string();
+        // `updated_at` has no `Option` slot of its own - it is not user-settable - and is only
+        // ever pushed guarded by `assignments` already being non-empty.
+        assert!(!generated.contains("set_updated_at"));
+        assert!(generated.contains("assignments") && generated.contains("is_empty"));
+        assert!(generated.contains("now ()") || generated.contains("now()"));
+    }
+
+    #[test]
+    fn apply_is_a_no_op_when_nothing_is_dirty() {
+        let generated = impl_track_changes(&sample_table::<Postgres>()).to_string();
+        assert!(generated.contains("return Ok (())") || generated.contains("return Ok(())"));
+    }
+
+    #[test]
+    fn placeholder_style_is_baked_in_per_backend() {
+        // `dollar_style` is resolved at macro-expansion time via `Backend::placeholder` and
+        // spliced in as a `true`/`false` literal guarding the `$n` vs `?` branch at runtime.
+        let postgres = impl_track_changes(&sample_table::<Postgres>()).to_string();
+        assert!(postgres.contains("if true"));
+
+        let mysql = impl_track_changes(&sample_table::<MySql>()).to_string();
+        assert!(mysql.contains("if false"));
+    }
+}
@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    backend::Backend,
+    table::{Table, TableField},
+};
+
+/// Generates the `UPDATE` statement issued by `Table::update`/`save`, setting every column except
+/// the id and any `#[ormx(created_at)]` column - and, when the field is an `#[ormx(updated_at)]` column, setting it to
+/// [`Backend::now_expr`] instead of binding the struct's value, since the database owns it.
+pub fn impl_update<B: Backend>(table: &Table<B>) -> TokenStream {
+    let table_name = B::quote_ident(&table.table);
+    let id_column = B::quote_ident(&table.id.column());
+
+    let settable_fields: Vec<&TableField> = table
+        .fields
+        .iter()
+        .filter(|field| !field.created_at && field.field != table.id.field)
+        .collect();
+
+    let mut placeholder_index = 0;
+    let assignments: Vec<String> = settable_fields
+        .iter()
+        .map(|field| {
+            let column = B::quote_ident(&field.column());
+            if field.updated_at {
+                format!("{} = {}", column, B::now_expr())
+            } else {
+                let placeholder = B::placeholder(placeholder_index);
+                placeholder_index += 1;
+                format!("{} = {}", column, placeholder)
+            }
+        })
+        .collect();
+
+    let sql = format!(
+        "UPDATE {} SET {} WHERE {} = {}",
+        table_name,
+        assignments.join(", "),
+        id_column,
+        B::placeholder(placeholder_index)
+    );
+
+    let bind_args: proc_macro2::TokenStream = settable_fields
+        .iter()
+        .filter(|field| !field.updated_at)
+        .map(|field| format!(" arguments.add(&self.{}); ", field.field))
+        .collect::<String>()
+        .parse()
+        .unwrap();
+
+    let id_field = &table.id.field;
+
+    quote! {
+        fn update_sql() -> &'static str {
+            #sql
+        }
+
+        fn update_arguments<'a>(&'a self, arguments: &mut cherry::types::Arguments<'a>) {
+            use cherry::sqlx::Arguments as OtherArguments;
+            #bind_args
+            arguments.add(&self.#id_field);
+        }
+    }
+}